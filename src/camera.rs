@@ -0,0 +1,125 @@
+use crate::ray::Ray;
+use crate::vec3::Vec3;
+
+pub struct Camera {
+    origin: Vec3,
+    lower_left_corner: Vec3,
+    horizontal: Vec3,
+    vertical: Vec3,
+    u: Vec3,
+    v: Vec3,
+    lens_radius: f64,
+    time0: f64,
+    time1: f64,
+}
+
+impl Camera {
+    /// `vfov` is the vertical field of view, in degrees. `aperture` and `focus_dist` control
+    /// the thin-lens defocus blur: a larger aperture blurs everything off the focal plane,
+    /// which sits `focus_dist` away from `look_from`. `time0`/`time1` are the shutter's open
+    /// interval; each ray picks a random time within it, which moving geometry can sample.
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        look_from: &Vec3,
+        look_at: &Vec3,
+        vup: &Vec3,
+        vfov: f64,
+        aspect_ratio: f64,
+        aperture: f64,
+        focus_dist: f64,
+        time0: f64,
+        time1: f64,
+    ) -> Self {
+        let theta = vfov.to_radians();
+        let h = (theta / 2.0).tan();
+        let viewport_height = 2.0 * h;
+        let viewport_width = aspect_ratio * viewport_height;
+
+        let w = (*look_from - *look_at).unit_vector();
+        let u = vup.cross(&w).unit_vector();
+        let v = w.cross(&u);
+
+        let origin = *look_from;
+        let horizontal = u * viewport_width * focus_dist;
+        let vertical = v * viewport_height * focus_dist;
+        let lower_left_corner = origin - horizontal / 2.0 - vertical / 2.0 - w * focus_dist;
+
+        Camera {
+            origin,
+            lower_left_corner,
+            horizontal,
+            vertical,
+            u,
+            v,
+            lens_radius: aperture / 2.0,
+            time0,
+            time1,
+        }
+    }
+
+    pub fn get_ray(&self, s: f64, t: f64) -> Ray {
+        let rd = Vec3::random_in_unit_disk() * self.lens_radius;
+        let offset = lens_offset(self.u, self.v, rd);
+        let time = self.time0 + fastrand::f64() * (self.time1 - self.time0);
+
+        Ray::new(
+            self.origin + offset,
+            self.lower_left_corner + self.horizontal * s + self.vertical * t - self.origin - offset,
+            time,
+        )
+    }
+}
+
+/// Projects a disk sample `rd` (as produced by `Vec3::random_in_unit_disk` scaled by the lens
+/// radius) onto the camera's `u`/`v` basis to get the lens-origin offset for a ray.
+fn lens_offset(u: Vec3, v: Vec3, rd: Vec3) -> Vec3 {
+    u * rd.x + v * rd.y
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lens_offset_projects_the_disk_sample_onto_the_camera_basis() {
+        let u = Vec3::new(1.0, 0.0, 0.0);
+        let v = Vec3::new(0.0, 1.0, 0.0);
+
+        assert_eq!(lens_offset(u, v, Vec3::new(0.0, 0.0, 0.0)), Vec3::new(0.0, 0.0, 0.0));
+        assert_eq!(lens_offset(u, v, Vec3::new(0.5, -0.25, 0.0)), Vec3::new(0.5, -0.25, 0.0));
+    }
+
+    fn assert_vec3_close(actual: Vec3, expected: Vec3) {
+        let diff = actual - expected;
+        assert!(
+            diff.length_squared() < 1e-12,
+            "expected {:?} to be close to {:?}",
+            actual,
+            expected
+        );
+    }
+
+    #[test]
+    fn vfov_90_matches_the_unit_viewport() {
+        // Zero aperture and a single-instant shutter make get_ray fully deterministic.
+        let camera = Camera::new(
+            &Vec3::new(0.0, 0.0, 0.0),
+            &Vec3::new(0.0, 0.0, -1.0),
+            &Vec3::new(0.0, 1.0, 0.0),
+            90.0,
+            16.0 / 9.0,
+            0.0,
+            1.0,
+            0.0,
+            0.0,
+        );
+
+        let bottom_left = camera.get_ray(0.0, 0.0);
+        assert_eq!(bottom_left.origin(), Vec3::new(0.0, 0.0, 0.0));
+        assert_vec3_close(bottom_left.direction(), Vec3::new(-16.0 / 9.0, -1.0, -1.0));
+        assert_eq!(bottom_left.time(), 0.0);
+
+        let top_right = camera.get_ray(1.0, 1.0);
+        assert_vec3_close(top_right.direction(), Vec3::new(16.0 / 9.0, 1.0, -1.0));
+    }
+}
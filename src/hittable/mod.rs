@@ -0,0 +1,56 @@
+pub mod moving_sphere;
+pub mod sphere;
+
+use std::sync::Arc;
+
+use crate::material::Material;
+use crate::ray::Ray;
+use crate::vec3::Vec3;
+
+#[derive(Clone)]
+pub struct HitRecord {
+    point: Vec3,
+    normal: Vec3,
+    t: f64,
+    front_face: bool,
+    material: Arc<dyn Material>,
+}
+
+impl HitRecord {
+    pub fn new(point: Vec3, t: f64, r: &Ray, outward_normal: Vec3, material: Arc<dyn Material>) -> Self {
+        let front_face = r.direction().dot(&outward_normal) < 0.0;
+        let normal = if front_face { outward_normal } else { -outward_normal };
+
+        HitRecord {
+            point,
+            normal,
+            t,
+            front_face,
+            material,
+        }
+    }
+
+    pub fn point(&self) -> Vec3 {
+        self.point
+    }
+
+    pub fn normal(&self) -> Vec3 {
+        self.normal
+    }
+
+    pub fn t_value(&self) -> f64 {
+        self.t
+    }
+
+    pub fn front_face(&self) -> bool {
+        self.front_face
+    }
+
+    pub fn material(&self) -> Arc<dyn Material> {
+        self.material.clone()
+    }
+}
+
+pub trait Hittable: Send + Sync {
+    fn hit(&self, r: &Ray, t_min: f64, t_max: f64) -> Option<HitRecord>;
+}
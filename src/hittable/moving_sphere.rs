@@ -0,0 +1,86 @@
+use std::sync::Arc;
+
+use crate::hittable::{HitRecord, Hittable};
+use crate::material::Material;
+use crate::ray::Ray;
+use crate::vec3::Vec3;
+
+pub struct MovingSphere {
+    center0: Vec3,
+    center1: Vec3,
+    time0: f64,
+    time1: f64,
+    radius: f64,
+    material: Arc<dyn Material>,
+}
+
+impl MovingSphere {
+    pub fn new(
+        center0: &Vec3,
+        center1: &Vec3,
+        time0: f64,
+        time1: f64,
+        radius: f64,
+        material: Arc<dyn Material>,
+    ) -> Self {
+        MovingSphere {
+            center0: *center0,
+            center1: *center1,
+            time0,
+            time1,
+            radius,
+            material,
+        }
+    }
+
+    pub fn center(&self, time: f64) -> Vec3 {
+        self.center0 + (self.center1 - self.center0) * ((time - self.time0) / (self.time1 - self.time0))
+    }
+}
+
+impl Hittable for MovingSphere {
+    fn hit(&self, r: &Ray, t_min: f64, t_max: f64) -> Option<HitRecord> {
+        let center = self.center(r.time());
+
+        let oc = r.origin() - center;
+        let a = r.direction().length_squared();
+        let half_b = oc.dot(&r.direction());
+        let c = oc.length_squared() - self.radius * self.radius;
+
+        let discriminant = half_b * half_b - a * c;
+        if discriminant < 0.0 {
+            return None;
+        }
+        let sqrtd = discriminant.sqrt();
+
+        let mut root = (-half_b - sqrtd) / a;
+        if root < t_min || root > t_max {
+            root = (-half_b + sqrtd) / a;
+            if root < t_min || root > t_max {
+                return None;
+            }
+        }
+
+        let point = r.at(root);
+        let outward_normal = (point - center) / self.radius;
+        Some(HitRecord::new(point, root, r, outward_normal, self.material.clone()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::material::Lambertian;
+
+    #[test]
+    fn center_lerps_between_endpoints_over_the_shutter_interval() {
+        let center0 = Vec3::new(0.0, 0.0, -1.0);
+        let center1 = Vec3::new(0.0, 1.0, -1.0);
+        let material: Arc<dyn Material> = Arc::new(Lambertian::new(&Vec3::new(0.5, 0.5, 0.5)));
+        let sphere = MovingSphere::new(&center0, &center1, 0.0, 1.0, 0.2, material);
+
+        assert_eq!(sphere.center(0.0), center0);
+        assert_eq!(sphere.center(1.0), center1);
+        assert_eq!(sphere.center(0.5), Vec3::new(0.0, 0.5, -1.0));
+    }
+}
@@ -0,0 +1,49 @@
+use std::sync::Arc;
+
+use crate::hittable::{HitRecord, Hittable};
+use crate::material::Material;
+use crate::ray::Ray;
+use crate::vec3::Vec3;
+
+pub struct Sphere {
+    center: Vec3,
+    radius: f64,
+    material: Arc<dyn Material>,
+}
+
+impl Sphere {
+    pub fn new(center: &Vec3, radius: f64, material: Arc<dyn Material>) -> Self {
+        Sphere {
+            center: *center,
+            radius,
+            material,
+        }
+    }
+}
+
+impl Hittable for Sphere {
+    fn hit(&self, r: &Ray, t_min: f64, t_max: f64) -> Option<HitRecord> {
+        let oc = r.origin() - self.center;
+        let a = r.direction().length_squared();
+        let half_b = oc.dot(&r.direction());
+        let c = oc.length_squared() - self.radius * self.radius;
+
+        let discriminant = half_b * half_b - a * c;
+        if discriminant < 0.0 {
+            return None;
+        }
+        let sqrtd = discriminant.sqrt();
+
+        let mut root = (-half_b - sqrtd) / a;
+        if root < t_min || root > t_max {
+            root = (-half_b + sqrtd) / a;
+            if root < t_min || root > t_max {
+                return None;
+            }
+        }
+
+        let point = r.at(root);
+        let outward_normal = (point - self.center) / self.radius;
+        Some(HitRecord::new(point, root, r, outward_normal, self.material.clone()))
+    }
+}
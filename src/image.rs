@@ -0,0 +1,85 @@
+use std::io;
+use std::path::Path;
+
+use image as image_crate;
+
+use crate::vec3::Vec3;
+
+#[derive(Debug, Clone, Copy)]
+pub struct Pixel {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+}
+
+impl Pixel {
+    pub fn new(r: u8, g: u8, b: u8) -> Self {
+        Pixel { r, g, b }
+    }
+
+    pub fn black() -> Self {
+        Pixel::new(0, 0, 0)
+    }
+
+    pub fn from_vec3(v: &Vec3) -> Self {
+        Pixel::new(v.x as u8, v.y as u8, v.z as u8)
+    }
+}
+
+pub struct Image {
+    height: usize,
+    width: usize,
+    pixels: Vec<Pixel>,
+}
+
+impl Image {
+    pub fn new(height: usize, width: usize) -> Self {
+        Image {
+            height,
+            width,
+            pixels: vec![Pixel::black(); height * width],
+        }
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn insert_pixel_at(&mut self, x: usize, y: usize, pixel: &Pixel) {
+        self.pixels[y * self.width + x] = *pixel;
+    }
+
+    pub fn to_ppm(&self) -> String {
+        let mut out = format!("P3\n{} {}\n255\n", self.width, self.height);
+        for pixel in &self.pixels {
+            out.push_str(&format!("{} {} {}\n", pixel.r, pixel.g, pixel.b));
+        }
+        out
+    }
+
+    /// Writes the image to `path`, picking the encoding from its extension. `.png` goes
+    /// through the `image` crate; anything else falls back to the original ASCII PPM.
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some("png") => self.save_png(path),
+            _ => std::fs::write(path, self.to_ppm()),
+        }
+    }
+
+    fn save_png(&self, path: &Path) -> io::Result<()> {
+        let mut buffer = image_crate::RgbImage::new(self.width as u32, self.height as u32);
+
+        for y in 0..self.height {
+            for x in 0..self.width {
+                let pixel = self.pixels[y * self.width + x];
+                buffer.put_pixel(x as u32, y as u32, image_crate::Rgb([pixel.r, pixel.g, pixel.b]));
+            }
+        }
+
+        buffer.save(path).map_err(io::Error::other)
+    }
+}
@@ -4,26 +4,30 @@ mod ray;
 mod hittable;
 mod camera;
 mod material;
+mod scene;
 
 use image::{Image, Pixel};
 use vec3::Vec3;
 use ray::Ray;
-use hittable::{HitRecord, Hittable, sphere::Sphere};
-use std::{fs::File, io::Write, iter::repeat_with, rc::Rc};
-use crate::{camera::Camera, material::{Lambertian, Dielectric}, material::Metal};
+use hittable::{HitRecord, Hittable};
+use std::{iter::repeat_with, path::PathBuf, sync::Arc, thread};
+use crossbeam_channel::unbounded;
+use indicatif::{ProgressBar, ProgressStyle};
+use crate::camera::Camera;
 
+type World = Vec<Box<dyn Hittable>>;
 
-fn trace_ray(r: &Ray, world: &Vec<Box<dyn Hittable>>, max_depth: usize) -> Pixel {
+fn trace_ray(r: &Ray, world: &World, max_depth: usize) -> Vec3 {
 
-    if max_depth <= 0 {
-        return Pixel::black();
+    if max_depth == 0 {
+        return Vec3::new(0.0, 0.0, 0.0);
     }
 
     let mut t_closest_so_far = f64::INFINITY;
     let mut rec: Option<HitRecord> = None;
 
     for obj in world {
-        let result = obj.hit(&r, 0.001, t_closest_so_far);
+        let result = obj.hit(r, 0.001, t_closest_so_far);
 
         if let Some(temp_rec) = result {
             t_closest_so_far = temp_rec.t_value();
@@ -34,100 +38,135 @@ fn trace_ray(r: &Ray, world: &Vec<Box<dyn Hittable>>, max_depth: usize) -> Pixel
     if let Some(final_rec) = rec {
 
         if let Some((attenuation, new_ray)) = final_rec.material().scatter(r, final_rec) {
-            let pixel = trace_ray(&new_ray, world, max_depth - 1);
-    
-            return Pixel::new(
-                (attenuation.x * pixel.r as f64) as u8,
-                (attenuation.y * pixel.g as f64) as u8,
-                (attenuation.z * pixel.b as f64) as u8
-            );
+            let color = trace_ray(&new_ray, world, max_depth - 1);
+            return attenuation * color;
         }
 
-        return Pixel::black();
+        return Vec3::new(0.0, 0.0, 0.0);
     }
 
     let w = 0.5*(r.direction().y + 1.0);
     let white: Vec3 = Vec3::new(1.0, 1.0, 1.0);
     let blue: Vec3 = Vec3::new(0.5, 0.7, 1.0);
-    let color = white*(1.0 - w) + blue*w;
-    Pixel::new(
-        (color.x * 255.0) as u8,
-        (color.y * 255.0) as u8,
-        (color.z * 255.0) as u8
-    )
+    white*(1.0 - w) + blue*w
+}
+
+#[allow(clippy::too_many_arguments)]
+fn render_pixel(
+    x: usize,
+    y: usize,
+    img_width: usize,
+    img_height: usize,
+    samples_per_pixel: usize,
+    max_depth: usize,
+    camera: &Camera,
+    world: &World,
+) -> Pixel {
+    let color: Vec3 = repeat_with(fastrand::f64)
+        .take(samples_per_pixel)
+        .map(|random_val| (
+            (x as f64 + random_val) / (img_width as f64 - 1.0),
+            (y as f64 + random_val) / (img_height as f64 - 1.0),
+        ))
+        .map(|(u, v)| camera.get_ray(u, v))
+        .map(|ray| trace_ray(&ray, world, max_depth))
+        .fold(Vec3::new(0.0, 0.0, 0.0), |acc, v| acc + v) / samples_per_pixel as f64;
+
+    Pixel::from_vec3(&tone_map(color))
+}
+
+/// Gamma-corrects an averaged linear sample (`sqrt`), clamps out-of-range components, then
+/// scales into the `[0, 256)` range a `Pixel` expects.
+fn tone_map(color: Vec3) -> Vec3 {
+    color.sqrt().clamp(0.0, 1.0) * 255.999
 }
 
 fn main() -> std::io::Result<()> {
     const ASPECT_RATIO: f64 = 16.0/9.0;
 
-    const VP_HEIGHT: f64 = 2.0;
-    const VP_WIDTH: f64 = VP_HEIGHT * ASPECT_RATIO;
-
     const IMG_HEIGHT: usize = 400;
     const IMG_WIDTH: usize = (IMG_HEIGHT as f64 * ASPECT_RATIO) as usize;
 
-    const FOCAL_LENGTH: f64 = 1.0;
+    const VFOV: f64 = 20.0;
+    const APERTURE: f64 = 0.1;
+    const TIME0: f64 = 0.0;
+    const TIME1: f64 = 1.0;
 
     const SAMPLES_PER_PIXEL: usize = 100;
 
     const MAX_DEPTH: usize = 50;
 
-    let origin = Vec3::new(0.0, 0.0, 0.0);
-    let camera = Camera::new(&origin, VP_HEIGHT, VP_WIDTH, FOCAL_LENGTH);
+    let look_from = Vec3::new(13.0, 2.0, 3.0);
+    let look_at = Vec3::new(0.0, 0.0, 0.0);
+    let vup = Vec3::new(0.0, 1.0, 0.0);
+    let focus_dist = 10.0;
+    let camera = Camera::new(
+        &look_from, &look_at, &vup, VFOV, ASPECT_RATIO, APERTURE, focus_dist, TIME0, TIME1,
+    );
 
     let mut image = Image::new(IMG_HEIGHT, IMG_WIDTH);
 
-    let material_ground = Rc::new(Lambertian::new(&Vec3::new(0.8, 0.8, 0.0)));
-    let material_left = Rc::new(Dielectric::new(1.5));
-    let material_right = Rc::new(Metal::new(&Vec3::new(0.8, 0.6, 0.2), 0.0));
-    let material_center = Rc::new(Lambertian::new(&Vec3::new(0.1, 0.2, 0.5)));
-
-    let sphere_center = Sphere::new(
-        &Vec3::new( 0.0, 0.0, -1.0),
-        0.5,
-        material_center
-    );
-
-    let sphere_left = Sphere::new(
-        &Vec3::new(-1.0, 0.0, -1.0),
-        0.5,
-        material_left
-    );
+    let world: World = scene::random_scene();
+    let world = Arc::new(world);
+    let camera = Arc::new(camera);
+    let num_workers = thread::available_parallelism().map(|n| n.get()).unwrap_or(1);
+    let img_width = image.width();
+    let img_height = image.height();
+
+    let (row_tx, row_rx) = unbounded::<usize>();
+    let (result_tx, result_rx) = unbounded::<(usize, Vec<Pixel>)>();
+
+    for _ in 0..num_workers {
+        let row_rx = row_rx.clone();
+        let result_tx = result_tx.clone();
+        let world = Arc::clone(&world);
+        let camera = Arc::clone(&camera);
+
+        thread::spawn(move || {
+            while let Ok(y) = row_rx.recv() {
+                let row = (0..img_width)
+                    .map(|x| render_pixel(x, y, img_width, img_height, SAMPLES_PER_PIXEL, MAX_DEPTH, &camera, &world))
+                    .collect();
+                result_tx.send((y, row)).expect("render result channel closed early");
+            }
+        });
+    }
+    drop(result_tx);
 
-    let sphere_right = Sphere::new(
-        &Vec3::new(1.0, 0.0, -1.0),
-        0.5,
-        material_right
-    );
+    for y in 0..image.height() {
+        row_tx.send(y).expect("render job channel closed early");
+    }
+    drop(row_tx);
 
-    let sphere_ground = Sphere::new(
-        &Vec3::new(0.0, -100.5, -1.0),
-        100.0,
-        material_ground
+    let progress = ProgressBar::new(image.height() as u64);
+    progress.set_style(
+        ProgressStyle::with_template("{bar:40} {pos}/{len} scanlines ({eta}) {msg}")
+            .unwrap(),
     );
 
-    let mut world: Vec<Box<dyn Hittable>> = Vec::new();
-    world.push(Box::new(sphere_ground));
-    world.push(Box::new(sphere_left));
-    world.push(Box::new(sphere_right));
-    world.push(Box::new(sphere_center));
-
-    for y in 0..image.height() {
-        for x in 0..image.width() {
-            let avg_sample: Vec3 = repeat_with(|| fastrand::f64())
-                                    .take(SAMPLES_PER_PIXEL)
-                                    .map(|random_val| ((x as f64 + random_val) / (image.width() as f64 - 1.0), (y as f64 + random_val) / (image.height() as f64 - 1.0)))
-                                    .map(|(u,v)| camera.get_ray(u, v))
-                                    .map(|ray| trace_ray(&ray, &world, MAX_DEPTH))
-                                    .map(|pixel| Vec3::new(pixel.r as f64, pixel.g as f64, pixel.b as f64))
-                                    .fold(Vec3::new(0.0, 0.0, 0.0), |acc, v| acc + v) / SAMPLES_PER_PIXEL as f64;
-            let avg_sample = (avg_sample / 255.0).sqrt() * 256.0;
-            let pixel = Pixel::from_vec3(&avg_sample);
+    for (y, row) in result_rx {
+        for (x, pixel) in row.into_iter().enumerate() {
             image.insert_pixel_at(x, image.height() - y - 1, &pixel);
         }
+        progress.inc(1);
     }
+    progress.finish_with_message("render complete");
+
+    let output_path = std::env::args()
+        .nth(1)
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from("test.ppm"));
+    image.save(&output_path)
+}
 
-    let mut f = File::create("test.ppm")?;
-    f.write_all(image.to_ppm().as_bytes())?;
-    Ok(())
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tone_map_applies_gamma_then_clamps_then_scales() {
+        assert_eq!(tone_map(Vec3::new(0.0, 0.25, 1.0)), Vec3::new(0.0, 0.5, 1.0) * 255.999);
+        // A component over 1.0 after gamma correction is clamped before scaling.
+        assert_eq!(tone_map(Vec3::new(4.0, 0.25, 0.0)), Vec3::new(1.0, 0.5, 0.0) * 255.999);
+    }
 }
\ No newline at end of file
@@ -0,0 +1,96 @@
+use crate::hittable::HitRecord;
+use crate::ray::Ray;
+use crate::vec3::Vec3;
+
+pub trait Material: Send + Sync {
+    fn scatter(&self, r_in: &Ray, rec: HitRecord) -> Option<(Vec3, Ray)>;
+}
+
+pub struct Lambertian {
+    albedo: Vec3,
+}
+
+impl Lambertian {
+    pub fn new(albedo: &Vec3) -> Self {
+        Lambertian { albedo: *albedo }
+    }
+}
+
+impl Material for Lambertian {
+    fn scatter(&self, r_in: &Ray, rec: HitRecord) -> Option<(Vec3, Ray)> {
+        let mut scatter_direction = rec.normal() + Vec3::random_unit_vector();
+
+        if scatter_direction.near_zero() {
+            scatter_direction = rec.normal();
+        }
+
+        let scattered = Ray::new(rec.point(), scatter_direction, r_in.time());
+        Some((self.albedo, scattered))
+    }
+}
+
+pub struct Metal {
+    albedo: Vec3,
+    fuzz: f64,
+}
+
+impl Metal {
+    pub fn new(albedo: &Vec3, fuzz: f64) -> Self {
+        Metal {
+            albedo: *albedo,
+            fuzz: fuzz.min(1.0),
+        }
+    }
+}
+
+impl Material for Metal {
+    fn scatter(&self, r_in: &Ray, rec: HitRecord) -> Option<(Vec3, Ray)> {
+        let reflected = r_in.direction().unit_vector().reflect(&rec.normal());
+        let scattered = Ray::new(
+            rec.point(),
+            reflected + Vec3::random_in_unit_sphere() * self.fuzz,
+            r_in.time(),
+        );
+
+        if scattered.direction().dot(&rec.normal()) > 0.0 {
+            Some((self.albedo, scattered))
+        } else {
+            None
+        }
+    }
+}
+
+pub struct Dielectric {
+    ir: f64,
+}
+
+impl Dielectric {
+    pub fn new(ir: f64) -> Self {
+        Dielectric { ir }
+    }
+
+    fn reflectance(cosine: f64, ref_idx: f64) -> f64 {
+        let r0 = ((1.0 - ref_idx) / (1.0 + ref_idx)).powi(2);
+        r0 + (1.0 - r0) * (1.0 - cosine).powi(5)
+    }
+}
+
+impl Material for Dielectric {
+    fn scatter(&self, r_in: &Ray, rec: HitRecord) -> Option<(Vec3, Ray)> {
+        let refraction_ratio = if rec.front_face() { 1.0 / self.ir } else { self.ir };
+
+        let unit_direction = r_in.direction().unit_vector();
+        let cos_theta = (-unit_direction).dot(&rec.normal()).min(1.0);
+        let sin_theta = (1.0 - cos_theta * cos_theta).sqrt();
+
+        let cannot_refract = refraction_ratio * sin_theta > 1.0;
+        let direction = if cannot_refract || Self::reflectance(cos_theta, refraction_ratio) > fastrand::f64() {
+            unit_direction.reflect(&rec.normal())
+        } else {
+            unit_direction.refract(&rec.normal(), refraction_ratio)
+        };
+
+        let scattered = Ray::new(rec.point(), direction, r_in.time());
+        Some((Vec3::new(1.0, 1.0, 1.0), scattered))
+    }
+}
@@ -0,0 +1,121 @@
+use std::sync::Arc;
+
+use crate::hittable::{moving_sphere::MovingSphere, sphere::Sphere, Hittable};
+use crate::material::{Dielectric, Lambertian, Metal};
+use crate::vec3::Vec3;
+
+/// Small spheres this close to `(4.0, 0.2, 0.0)` are skipped so they don't crowd the big metal
+/// feature sphere placed there.
+fn is_excluded(center: Vec3) -> bool {
+    (center - Vec3::new(4.0, 0.2, 0.0)).length() <= 0.9
+}
+
+enum MaterialKind {
+    Lambertian,
+    Metal,
+    Dielectric,
+}
+
+/// Maps a `[0, 1)` roll to a material kind using the classic 80/15/5 diffuse/metal/glass split.
+fn material_kind(choose_material: f64) -> MaterialKind {
+    if choose_material < 0.8 {
+        MaterialKind::Lambertian
+    } else if choose_material < 0.95 {
+        MaterialKind::Metal
+    } else {
+        MaterialKind::Dielectric
+    }
+}
+
+/// Builds the classic "in one weekend" showcase scene: a large ground sphere, a dense grid of
+/// small random spheres, and three big feature spheres (glass, diffuse, metal).
+pub fn random_scene() -> Vec<Box<dyn Hittable>> {
+    let mut world: Vec<Box<dyn Hittable>> = Vec::new();
+
+    let ground_material = Arc::new(Lambertian::new(&Vec3::new(0.5, 0.5, 0.5)));
+    world.push(Box::new(Sphere::new(
+        &Vec3::new(0.0, -1000.0, 0.0),
+        1000.0,
+        ground_material,
+    )));
+
+    for a in -11..11 {
+        for b in -11..11 {
+            let center = Vec3::new(
+                a as f64 + 0.9 * fastrand::f64(),
+                0.2,
+                b as f64 + 0.9 * fastrand::f64(),
+            );
+
+            if is_excluded(center) {
+                continue;
+            }
+
+            let sphere: Box<dyn Hittable> = match material_kind(fastrand::f64()) {
+                MaterialKind::Lambertian => {
+                    let albedo = Vec3::random() * Vec3::random();
+                    let center2 = center + Vec3::new(0.0, fastrand::f64() * 0.5, 0.0);
+                    Box::new(MovingSphere::new(
+                        &center,
+                        &center2,
+                        0.0,
+                        1.0,
+                        0.2,
+                        Arc::new(Lambertian::new(&albedo)),
+                    ))
+                }
+                MaterialKind::Metal => {
+                    let albedo = Vec3::random_range(0.5, 1.0);
+                    let fuzz = fastrand::f64() * 0.5;
+                    Box::new(Sphere::new(&center, 0.2, Arc::new(Metal::new(&albedo, fuzz))))
+                }
+                MaterialKind::Dielectric => {
+                    Box::new(Sphere::new(&center, 0.2, Arc::new(Dielectric::new(1.5))))
+                }
+            };
+
+            world.push(sphere);
+        }
+    }
+
+    world.push(Box::new(Sphere::new(
+        &Vec3::new(0.0, 1.0, 0.0),
+        1.0,
+        Arc::new(Dielectric::new(1.5)),
+    )));
+    world.push(Box::new(Sphere::new(
+        &Vec3::new(-4.0, 1.0, 0.0),
+        1.0,
+        Arc::new(Lambertian::new(&Vec3::new(0.4, 0.2, 0.1))),
+    )));
+    world.push(Box::new(Sphere::new(
+        &Vec3::new(4.0, 1.0, 0.0),
+        1.0,
+        Arc::new(Metal::new(&Vec3::new(0.7, 0.6, 0.5), 0.0)),
+    )));
+
+    world
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn excludes_only_the_feature_sphere_neighborhood() {
+        assert!(is_excluded(Vec3::new(4.0, 0.2, 0.0)));
+        assert!(is_excluded(Vec3::new(4.5, 0.2, 0.0)));
+        assert!(!is_excluded(Vec3::new(0.0, 0.2, 0.0)));
+        assert!(!is_excluded(Vec3::new(-4.0, 0.2, 0.0)));
+    }
+
+    #[test]
+    fn material_kind_follows_the_80_15_5_split() {
+        assert!(matches!(material_kind(0.0), MaterialKind::Lambertian));
+        assert!(matches!(material_kind(0.79), MaterialKind::Lambertian));
+        assert!(matches!(material_kind(0.8), MaterialKind::Metal));
+        assert!(matches!(material_kind(0.94), MaterialKind::Metal));
+        assert!(matches!(material_kind(0.95), MaterialKind::Dielectric));
+        assert!(matches!(material_kind(0.999), MaterialKind::Dielectric));
+    }
+}